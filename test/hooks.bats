@@ -0,0 +1,33 @@
+#!/usr/bin/env bats
+
+load test_helper
+
+setup() {
+  load_script
+  write_config "hook.window-created = \"echo \$GH_GHQ_CD_PANE_ID \$GH_GHQ_CD_REPO_PATH >>${BATS_TEST_TMPDIR}/created\""
+  dir="${BATS_TEST_TMPDIR}/src/github.com/acme/api"
+  mkdir -p "${dir}"
+  export TMUX="${BATS_TEST_TMPDIR}/socket,1,0" SHELL=/bin/sh
+}
+
+@test "a new window runs the window-created hook with its pane" {
+  run new_window "${dir}"
+  [ "${status}" -eq 0 ]
+  [ "$(cat "${BATS_TEST_TMPDIR}/created")" = "%1 ${dir}" ]
+}
+
+@test "a new pane runs the window-created hook with its pane" {
+  run split_pane "${dir}"
+  [ "${status}" -eq 0 ]
+  [ "$(cat "${BATS_TEST_TMPDIR}/created")" = "%1 ${dir}" ]
+}
+
+@test "restored windows run the window-created hook each" {
+  local other="${BATS_TEST_TMPDIR}/src/github.com/acme/web"
+  mkdir -p "${other}" "$(dirname "$(opened_file)")"
+  printf '%s\n' "${dir}" "${other}" >"$(opened_file)"
+  run restore
+  [ "${status}" -eq 0 ]
+  [ "$(wc -l <"${BATS_TEST_TMPDIR}/created")" -eq 2 ]
+  grep -q -x -F "%1 ${other}" "${BATS_TEST_TMPDIR}/created"
+}
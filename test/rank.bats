@@ -0,0 +1,108 @@
+#!/usr/bin/env bats
+
+load test_helper
+
+setup() {
+  load_script
+  frecency="${BATS_TEST_TMPDIR}/frecency"
+  printf '%s\t%s\n' /src/a 1 /src/b 4 /src/c 2 >"${frecency}"
+}
+
+@test "without weight the fuzzy rank alone scores" {
+  run blend_scores 0 "${frecency}" <<<"/src/a
+/src/b
+/src/c
+/src/d"
+  [ "${status}" -eq 0 ]
+  [ "${lines[0]}" = "$(printf '1.000000\t/src/a')" ]
+  [ "${lines[1]}" = "$(printf '0.750000\t/src/b')" ]
+  [ "${lines[2]}" = "$(printf '0.500000\t/src/c')" ]
+  [ "${lines[3]}" = "$(printf '0.250000\t/src/d')" ]
+}
+
+@test "with full weight the frecency relative to the highest one alone scores" {
+  run blend_scores 1 "${frecency}" <<<"/src/a
+/src/b
+/src/c
+/src/d"
+  [ "${status}" -eq 0 ]
+  [ "${lines[0]}" = "$(printf '0.250000\t/src/a')" ]
+  [ "${lines[1]}" = "$(printf '1.000000\t/src/b')" ]
+  [ "${lines[2]}" = "$(printf '0.500000\t/src/c')" ]
+  [ "${lines[3]}" = "$(printf '0.000000\t/src/d')" ]
+}
+
+@test "the weight blends both scores" {
+  run blend_scores 0.5 "${frecency}" <<<"/src/a
+/src/b"
+  [ "${status}" -eq 0 ]
+  [ "${lines[0]}" = "$(printf '0.625000\t/src/a')" ]
+  [ "${lines[1]}" = "$(printf '0.750000\t/src/b')" ]
+}
+
+@test "without any history the fuzzy rank is kept" {
+  run blend_scores 0.5 /dev/null <<<"/src/a
+/src/b"
+  [ "${status}" -eq 0 ]
+  [ "${lines[0]}" = "$(printf '0.500000\t/src/a')" ]
+  [ "${lines[1]}" = "$(printf '0.250000\t/src/b')" ]
+}
+
+@test "the annotations of a candidate are kept and not taken for its path" {
+  run blend_scores 1 "${frecency}" <<<"$(printf '/src/b\t[work]\t[main*]')"
+  [ "${status}" -eq 0 ]
+  [ "${output}" = "$(printf '1.000000\t/src/b\t[work]\t[main*]')" ]
+}
+
+@test "no candidates score nothing" {
+  run blend_scores 0.5 "${frecency}" </dev/null
+  [ "${status}" -eq 0 ]
+  [ -z "${output}" ]
+}
+
+@test "frequently visited repositories win over equally good matches" {
+  local now
+  now="$(date +%s)"
+  mkdir -p "${state_dir}"
+  printf '%s\t%s\n' "${now}" /src/b/cli "${now}" /src/b/cli >"${history_file}"
+  frecency_weight=0.5
+  run rank_repos cli <<<"/src/a/cli
+/src/b/cli
+/src/c/other"
+  [ "${status}" -eq 0 ]
+  [ "${#lines[@]}" -eq 2 ]
+  [ "${lines[0]}" = "/src/b/cli" ]
+  [ "${lines[1]}" = "/src/a/cli" ]
+}
+
+@test "without history the order of fzf is kept" {
+  frecency_weight=0.5
+  run rank_repos cli <<<"/src/a/cli
+/src/b/cli"
+  [ "${status}" -eq 0 ]
+  [ "${lines[0]}" = "/src/a/cli" ]
+  [ "${lines[1]}" = "/src/b/cli" ]
+}
+
+@test "without weight or query the candidates are passed through" {
+  frecency_weight=0
+  run rank_repos cli <<<"/src/a/cli
+/src/c/other"
+  [ "${status}" -eq 0 ]
+  [ "${#lines[@]}" -eq 2 ]
+  frecency_weight=0.5
+  run rank_repos "" <<<"/src/a/cli
+/src/c/other"
+  [ "${status}" -eq 0 ]
+  [ "${#lines[@]}" -eq 2 ]
+}
+
+@test "reloads rank the candidates kept by the picker without listing them again" {
+  state_file="${BATS_TEST_TMPDIR}/state_file"
+  frecency_weight=0.5
+  save_state
+  printf '%s\t%s\n' /src/a/cli "[main]" /src/c/other "[main]" >"${state_file}.candidates"
+  GHQ_REPOS="" run "${BATS_TEST_DIRNAME}/../gh-ghq-cd" --_rank "${state_file}" cli
+  [ "${status}" -eq 0 ]
+  [ "${output}" = "$(printf '/src/a/cli\t[main]')" ]
+}
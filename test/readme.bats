@@ -0,0 +1,38 @@
+#!/usr/bin/env bats
+
+load test_helper
+
+setup() {
+  load_script
+  write_config "strip_badges = true"
+  readme="${BATS_TEST_TMPDIR}/README.md"
+}
+
+@test "badges, images, tags and shortcodes are stripped from prose" {
+  printf '%s\n' "# cli :rocket:" "" "[![CI](https://ci/badge.svg)](https://ci) ![logo](logo.png)" "" \
+    "See <b>the docs</b> :tada: now." >"${readme}"
+  run readme_text "${readme}"
+  [ "${status}" -eq 0 ]
+  [ "${output}" = "$(printf '%s\n' "# cli" "" "See the docs now.")" ]
+}
+
+@test "code spans are left as they are" {
+  printf '%s\n' 'Returns `std::io::Result` or `Vec<String>`, as `<owner>/<name>`  :tada:' >"${readme}"
+  run readme_text "${readme}"
+  [ "${status}" -eq 0 ]
+  [ "${output}" = 'Returns `std::io::Result` or `Vec<String>`, as `<owner>/<name>`' ]
+}
+
+@test "code blocks are left as they are" {
+  printf '%s\n' '```rust' 'fn f() -> Vec<String> {}' '// :smile:  <owner>/<name>' '```' >"${readme}"
+  run readme_text "${readme}"
+  [ "${status}" -eq 0 ]
+  [ "${output}" = "$(cat "${readme}")" ]
+}
+
+@test "colons next to a shortcode keep it" {
+  printf '%s\n' "Use std::io::Result and Foo::bar::baz here." >"${readme}"
+  run readme_text "${readme}"
+  [ "${status}" -eq 0 ]
+  [ "${output}" = "Use std::io::Result and Foo::bar::baz here." ]
+}
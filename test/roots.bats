@@ -0,0 +1,81 @@
+#!/usr/bin/env bats
+
+load test_helper
+
+setup() {
+  load_script
+  mkdir -p "${BATS_TEST_TMPDIR}/ghq/github.com/cli/cli" "${BATS_TEST_TMPDIR}/work/github.com/acme/api"
+  ln -s "${BATS_TEST_TMPDIR}/ghq" "${BATS_TEST_TMPDIR}/link"
+}
+
+@test "a repository reachable through a symlinked root is listed once" {
+  export GHQ_ROOTS="${BATS_TEST_TMPDIR}/ghq
+${BATS_TEST_TMPDIR}/link"
+  run dedupe_repos <<<"${BATS_TEST_TMPDIR}/ghq/github.com/cli/cli
+${BATS_TEST_TMPDIR}/link/github.com/cli/cli"
+  [ "${status}" -eq 0 ]
+  [ "${output}" = "${BATS_TEST_TMPDIR}/ghq/github.com/cli/cli" ]
+}
+
+@test "the first listed entry of a repository is kept" {
+  export GHQ_ROOTS="${BATS_TEST_TMPDIR}/ghq
+${BATS_TEST_TMPDIR}/link"
+  run dedupe_repos <<<"${BATS_TEST_TMPDIR}/link/github.com/cli/cli
+${BATS_TEST_TMPDIR}/ghq/github.com/cli/cli"
+  [ "${status}" -eq 0 ]
+  [ "${output}" = "${BATS_TEST_TMPDIR}/link/github.com/cli/cli" ]
+}
+
+@test "a symlink nested in another root is resolved too" {
+  mkdir -p "${BATS_TEST_TMPDIR}/ghq/mirror"
+  ln -s "${BATS_TEST_TMPDIR}/work" "${BATS_TEST_TMPDIR}/ghq/mirror/work"
+  export GHQ_ROOTS="${BATS_TEST_TMPDIR}/work
+${BATS_TEST_TMPDIR}/ghq/mirror/work"
+  run dedupe_repos <<<"${BATS_TEST_TMPDIR}/work/github.com/acme/api
+${BATS_TEST_TMPDIR}/ghq/mirror/work/github.com/acme/api"
+  [ "${status}" -eq 0 ]
+  [ "${output}" = "${BATS_TEST_TMPDIR}/work/github.com/acme/api" ]
+}
+
+@test "repositories of the same name under distinct roots are all kept" {
+  mkdir -p "${BATS_TEST_TMPDIR}/work/github.com/cli/cli"
+  export GHQ_ROOTS="${BATS_TEST_TMPDIR}/ghq
+${BATS_TEST_TMPDIR}/work"
+  run dedupe_repos <<<"${BATS_TEST_TMPDIR}/ghq/github.com/cli/cli
+${BATS_TEST_TMPDIR}/work/github.com/cli/cli"
+  [ "${status}" -eq 0 ]
+  [ "${#lines[@]}" -eq 2 ]
+}
+
+@test "a missing root does not drop repositories" {
+  export GHQ_ROOTS="${BATS_TEST_TMPDIR}/ghq
+${BATS_TEST_TMPDIR}/gone"
+  run dedupe_repos <<<"${BATS_TEST_TMPDIR}/ghq/github.com/cli/cli
+${BATS_TEST_TMPDIR}/gone/github.com/cli/cli"
+  [ "${status}" -eq 0 ]
+  [ "${#lines[@]}" -eq 2 ]
+}
+
+@test "the entry under the configured root with the highest priority is kept" {
+  export GHQ_ROOTS="${BATS_TEST_TMPDIR}/ghq
+${BATS_TEST_TMPDIR}/link"
+  write_config "root.main = ${BATS_TEST_TMPDIR}/link" "root.other = ${BATS_TEST_TMPDIR}/ghq"
+  run organize_repos <<<"${BATS_TEST_TMPDIR}/ghq/github.com/cli/cli
+${BATS_TEST_TMPDIR}/link/github.com/cli/cli"
+  [ "${status}" -eq 0 ]
+  [ "${output}" = "${BATS_TEST_TMPDIR}/link/github.com/cli/cli" ]
+}
+
+@test "the picker lists each repository of overlapping roots once" {
+  export GHQ_ROOTS="${BATS_TEST_TMPDIR}/ghq
+${BATS_TEST_TMPDIR}/link
+${BATS_TEST_TMPDIR}/work"
+  export GHQ_REPOS="${BATS_TEST_TMPDIR}/ghq/github.com/cli/cli
+${BATS_TEST_TMPDIR}/link/github.com/cli/cli
+${BATS_TEST_TMPDIR}/work/github.com/acme/api"
+  run list_repos
+  [ "${status}" -eq 0 ]
+  [ "${#lines[@]}" -eq 2 ]
+  [ "${lines[0]}" = "${BATS_TEST_TMPDIR}/ghq/github.com/cli/cli" ]
+  [ "${lines[1]}" = "${BATS_TEST_TMPDIR}/work/github.com/acme/api" ]
+}
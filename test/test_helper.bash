@@ -0,0 +1,24 @@
+# Loads the functions of gh-ghq-cd without running it, isolated from the
+# configuration, state and caches of the user, with the stubs of test/stubs
+# first on PATH.
+load_script() {
+  local var
+  for var in $(compgen -v GH_GHQ_CD_); do
+    unset "${var}"
+  done
+  unset TMUX TMUX_PANE
+  export XDG_CONFIG_HOME="${BATS_TEST_TMPDIR}/config"
+  export XDG_STATE_HOME="${BATS_TEST_TMPDIR}/state"
+  export XDG_CACHE_HOME="${BATS_TEST_TMPDIR}/cache"
+  export GIT_CONFIG_GLOBAL=/dev/null GIT_CONFIG_NOSYSTEM=1
+  export TMUX_LOG="${BATS_TEST_TMPDIR}/tmux.log"
+  PATH="${BATS_TEST_DIRNAME}/stubs:${PATH}"
+  # shellcheck source=../gh-ghq-cd
+  source "${BATS_TEST_DIRNAME}/../gh-ghq-cd"
+}
+
+# Writes the given lines to the config file.
+write_config() {
+  mkdir -p "${XDG_CONFIG_HOME}/gh-ghq-cd"
+  printf '%s\n' "$@" >"${XDG_CONFIG_HOME}/gh-ghq-cd/config"
+}
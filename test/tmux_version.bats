@@ -0,0 +1,101 @@
+#!/usr/bin/env bats
+
+load test_helper
+
+setup() {
+  load_script
+  export TMUX="${BATS_TEST_TMPDIR}/socket,1,0"
+}
+
+@test "the letter of a release is dropped" {
+  TMUX_VERSION="tmux 3.3a" run tmux_version
+  [ "${status}" -eq 0 ]
+  [ "${output}" = "3.3" ]
+}
+
+@test "a plain release is parsed" {
+  TMUX_VERSION="tmux 1.9" run tmux_version
+  [ "${status}" -eq 0 ]
+  [ "${output}" = "1.9" ]
+}
+
+@test "a prerelease is taken as its version" {
+  TMUX_VERSION="tmux next-3.4" run tmux_version
+  [ "${status}" -eq 0 ]
+  [ "${output}" = "3.4" ]
+  TMUX_VERSION="tmux 3.4-rc" run tmux_version
+  [ "${status}" -eq 0 ]
+  [ "${output}" = "3.4" ]
+}
+
+@test "a build from master is taken as the newest" {
+  TMUX_VERSION="tmux master" run tmux_version
+  [ "${status}" -eq 0 ]
+  [ "${output}" = "999" ]
+}
+
+@test "versions are compared by major then minor number" {
+  local cases=(
+    "tmux 3.3a 3.0 0"
+    "tmux 3.0 3.0 0"
+    "tmux 2.9a 3.0 1"
+    "tmux 2.6 2.6 0"
+    "tmux 2.10 2.9 0"
+    "tmux 1.8 1.9 1"
+    "tmux 10.0 3.0 0"
+    "tmux next-3.4 3.0 0"
+    "tmux master 3.0 0"
+  )
+  local entry
+  for entry in "${cases[@]}"; do
+    read -r name version least expected <<<"${entry}"
+    TMUX_VERSION="${name} ${version}" run tmux_at_least "${least}"
+    [ "${status}" -eq "${expected}" ] || {
+      echo "${version} at least ${least}: ${status}" >&2
+      false
+    }
+  done
+}
+
+@test "tmux older than 1.9 is ignored with a warning" {
+  TMUX_VERSION="tmux 1.8" run in_tmux
+  [ "${status}" -eq 1 ]
+  [[ "${output}" == *"tmux 1.8 is too old"* ]]
+  TMUX_VERSION="tmux 1.9" run in_tmux
+  [ "${status}" -eq 0 ]
+}
+
+@test "tmux 3.0 and later set variables of the new pane with -e" {
+  local tmux_cmd tmux_env
+  launch_dir=/home
+  TMUX_VERSION="tmux 3.0" tmux_command /src/cli sh -c "echo hi"
+  [ "${tmux_env[0]}" = "-e" ]
+  [ "${tmux_env[1]}" = "OLDPWD=/home" ]
+  [ "${tmux_env[3]}" = "GH_GHQ_CD_REPO_PATH=/src/cli" ]
+  [ "${#tmux_cmd[@]}" -eq 3 ]
+}
+
+@test "tmux before 3.0 gets the command as a single string" {
+  local tmux_cmd tmux_env
+  TMUX_VERSION="tmux 2.9a" tmux_command /src/cli sh -c "echo hi"
+  [ "${#tmux_env[@]}" -eq 0 ]
+  [ "${#tmux_cmd[@]}" -eq 1 ]
+  [ "${tmux_cmd[0]}" = "'sh' '-c' 'echo hi'" ]
+}
+
+@test "pane titles are skipped with a warning before tmux 2.6" {
+  local tmux_batch=()
+  write_config 'pane_title = "{name}"'
+  TMUX_VERSION="tmux 2.5" run set_pane_title %1 /src/cli
+  [ "${status}" -eq 0 ]
+  [[ "${output}" == *"pane titles need tmux 2.6 or later, found 2.5"* ]]
+  TMUX_VERSION="tmux 2.6" set_pane_title %1 /src/cli
+  [ "${tmux_batch[*]}" = "select-pane -t %1 -T cli" ]
+}
+
+@test "the version is queried once" {
+  TMUX_VERSION="tmux 2.9" tmux_at_least 1.9
+  TMUX_VERSION="tmux 1.8" run tmux_at_least 1.9
+  [ "${status}" -eq 0 ]
+  [ "${tmux_release}" = "2.9" ]
+}
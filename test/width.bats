@@ -0,0 +1,57 @@
+#!/usr/bin/env bats
+
+load test_helper
+
+setup() {
+  load_script
+  export LC_ALL=C.UTF-8
+}
+
+@test "ascii takes a column per character" {
+  run display_width "cli/cli"
+  [ "${status}" -eq 0 ]
+  [ "${output}" -eq 7 ]
+}
+
+@test "CJK and emoji take two columns" {
+  run display_width "日本語"
+  [ "${output}" -eq 6 ]
+  run display_width "🚀 ☕"
+  [ "${output}" -eq 5 ]
+}
+
+@test "a flag takes two columns and two flags four" {
+  run display_width "🇯🇵"
+  [ "${output}" -eq 2 ]
+  run display_width "🇯🇵🇺🇸"
+  [ "${output}" -eq 4 ]
+}
+
+@test "skin tones, combining marks and variation selectors take no column" {
+  run display_width "👍🏽"
+  [ "${output}" -eq 2 ]
+  run display_width "$(printf 'e\u0301')"
+  [ "${output}" -eq 1 ]
+  run display_width "❤️"
+  [ "${output}" -eq 2 ]
+}
+
+@test "a string fitting the width is kept" {
+  run truncate_width "日本語" 6
+  [ "${status}" -eq 0 ]
+  [ "${output}" = "日本語" ]
+}
+
+@test "a longer string is cut before a wide character overflows" {
+  run truncate_width "日本語" 5
+  [ "${output}" = "日本…" ]
+  run truncate_width "日本語" 4
+  [ "${output}" = "日…" ]
+  run truncate_width "a🚀🚀" 4
+  [ "${output}" = "a🚀…" ]
+}
+
+@test "a flag is not split in half" {
+  run truncate_width "🇯🇵🇺🇸x" 4
+  [ "${output}" = "🇯🇵…" ]
+}
@@ -0,0 +1,83 @@
+#!/usr/bin/env bats
+
+load test_helper
+
+setup() {
+  load_script
+}
+
+@test "dots, colons and quotes are replaced" {
+  run window_name /src/github.com/acme/a.b:c\'d\"e
+  [ "${status}" -eq 0 ]
+  [ "${output}" = "a_b_c_d_e" ]
+}
+
+@test "whitespace and control characters are replaced" {
+  run window_name "/src/github.com/acme/my repo$(printf '\t\033')x"
+  [ "${status}" -eq 0 ]
+  [ "${output}" = "my_repo__x" ]
+}
+
+@test "a leading character with a meaning in tmux targets is replaced" {
+  local name
+  for name in =exact @window %pane '$session' '~marked' '{last}' '!last' +next; do
+    run window_name "/src/github.com/acme/${name}"
+    [ "${status}" -eq 0 ]
+    [ "${output}" = "_${name:1}" ]
+  done
+}
+
+@test "the same characters are kept after the first one" {
+  run window_name /src/github.com/acme/a=b@c%d
+  [ "${status}" -eq 0 ]
+  [ "${output}" = "a=b@c%d" ]
+}
+
+@test "an explicit name is sanitized too" {
+  run window_name /src/github.com/acme/cli "team: cli.v2"
+  [ "${status}" -eq 0 ]
+  [ "${output}" = "team__cli_v2" ]
+}
+
+@test "the replacement character is configurable" {
+  write_config 'window_name_replacement = "-"'
+  run window_name /src/github.com/acme/a.b:c
+  [ "${status}" -eq 0 ]
+  [ "${output}" = "a-b-c" ]
+}
+
+@test "long names are truncated to window_name_max columns" {
+  write_config "window_name_max = 8"
+  run window_name /src/github.com/acme/a-very-long-name
+  [ "${status}" -eq 0 ]
+  [ "${output}" = "a-very-…" ]
+}
+
+@test "a name made of replaced characters is not empty" {
+  run window_name /src/github.com/acme/...
+  [ "${status}" -eq 0 ]
+  [ "${output}" = "___" ]
+  run window_name /src/github.com/acme/cli ""
+  [ "${status}" -eq 0 ]
+  [ "${output}" = "cli" ]
+}
+
+@test "a trailing semicolon is escaped in batched tmux commands" {
+  local tmux_batch=()
+  batch_tmux set-window-option -t %1 @ghq_cd_path "/src/a;"
+  batch_tmux select-pane -t %1 -T "b;"
+  [ "${tmux_batch[4]}" = '/src/a\;' ]
+  [ "${tmux_batch[5]}" = ";" ]
+  [ "${tmux_batch[10]}" = 'b\;' ]
+}
+
+@test "a new window of a hostile repository gets a sanitized name and exact tags" {
+  local dir="${BATS_TEST_TMPDIR}/src/github.com/acme/.evil:name;"
+  mkdir -p "${dir}"
+  export TMUX="${BATS_TEST_TMPDIR}/socket,1,0" SHELL=/bin/sh
+  run new_window "${dir}"
+  [ "${status}" -eq 0 ]
+  grep -F -q "[-c] [${dir%;}\;]" "${TMUX_LOG}"
+  grep -F -q "[-n] [_evil_name\;]" "${TMUX_LOG}"
+  grep -F -q "[@ghq_cd_path] [${dir%;}\;]" "${TMUX_LOG}"
+}